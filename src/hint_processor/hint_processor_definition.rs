@@ -1,11 +1,31 @@
 use crate::serde::deserialize_program::ApTracking;
+use crate::types::relocatable::Relocatable;
 use crate::types::{exec_scope::ExecutionScopes, instruction::Register};
-use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::errors::hint_errors::HintError;
 use crate::vm::vm_core::VirtualMachine;
 use num_bigint::BigInt;
 use std::any::Any;
 use std::collections::HashMap;
 
+//Maps the pc of a dynamically loaded program segment to the compiled hint data (as produced by
+//compile_hint) that should run at that pc. Returned by execute_hint_extensive so a running hint
+//can inject hints for a sub-program it just loaded (e.g. a bootloader loading a task).
+pub type HintExtension = HashMap<Relocatable, Vec<Box<dyn Any>>>;
+
+//Merges a HintExtension into a pc-keyed hint dispatch table of the same shape the VM steps
+//through. Only tests call it: the VM's step loop does not apply hint extensions itself.
+pub fn apply_hint_extension(
+    hint_data_dictionary: &mut HashMap<Relocatable, Vec<Box<dyn Any>>>,
+    hint_extension: HintExtension,
+) {
+    for (pc, mut hint_data) in hint_extension {
+        hint_data_dictionary
+            .entry(pc)
+            .or_insert_with(Vec::new)
+            .append(&mut hint_data);
+    }
+}
+
 pub trait HintProcessor {
     //Executes the hint which's data is provided by a dynamic structure previously created by compile_hint
     fn execute_hint(
@@ -20,7 +40,23 @@ pub trait HintProcessor {
         hint_data: &Box<dyn Any>,
         //Constant values extracted from the program specification.
         constants: &HashMap<String, BigInt>,
-    ) -> Result<(), VirtualMachineError>;
+    ) -> Result<(), HintError>;
+
+    //Same as execute_hint, but also allows the hint to extend the hint dispatch table with hints
+    //for a pc range it just loaded (e.g. a nested/loaded Cairo program). Defaults to running
+    //execute_hint and returning no extension, so existing processors need not implement this.
+    //Only tests call this: the VM's step loop calls execute_hint and does not pass any
+    //extension to apply_hint_extension.
+    fn execute_hint_extensive(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, BigInt>,
+    ) -> Result<HintExtension, HintError> {
+        self.execute_hint(vm, exec_scopes, hint_data, constants)?;
+        Ok(HintExtension::new())
+    }
 
     //Transforms hint data outputed by the VM into whichever format will be later used by execute_hint
     fn compile_hint(
@@ -34,7 +70,7 @@ pub trait HintProcessor {
         reference_ids: &HashMap<String, usize>,
         //List of all references (key corresponds to element of the previous dictionary)
         references: &HashMap<usize, HintReference>,
-    ) -> Result<Box<dyn Any>, VirtualMachineError>;
+    ) -> Result<Box<dyn Any>, HintError>;
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -76,3 +112,65 @@ impl HintReference {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relocatable;
+    use crate::utils::test_utils::*;
+
+    struct DummyHintProcessor;
+
+    impl HintProcessor for DummyHintProcessor {
+        fn execute_hint(
+            &mut self,
+            _vm: &mut VirtualMachine,
+            _exec_scopes: &mut ExecutionScopes,
+            _hint_data: &Box<dyn Any>,
+            _constants: &HashMap<String, BigInt>,
+        ) -> Result<(), HintError> {
+            Ok(())
+        }
+
+        fn compile_hint(
+            &self,
+            _hint_code: &str,
+            _ap_tracking_data: &ApTracking,
+            _reference_ids: &HashMap<String, usize>,
+            _references: &HashMap<usize, HintReference>,
+        ) -> Result<Box<dyn Any>, HintError> {
+            Ok(Box::new(()))
+        }
+    }
+
+    #[test]
+    fn execute_hint_extensive_default_returns_empty_extension() {
+        let mut vm = vm!();
+        let mut exec_scopes = ExecutionScopes::new();
+        let mut processor = DummyHintProcessor;
+        let hint_data: Box<dyn Any> = Box::new(());
+
+        let extension = processor
+            .execute_hint_extensive(&mut vm, &mut exec_scopes, &hint_data, &HashMap::new())
+            .unwrap();
+
+        assert!(extension.is_empty());
+    }
+
+    #[test]
+    fn apply_hint_extension_appends_to_existing_pc_and_adds_new_one() {
+        let pc_a = relocatable!(0, 1);
+        let pc_b = relocatable!(0, 2);
+        let existing_hint: Box<dyn Any> = Box::new(1_u8);
+        let mut hint_data_dictionary = HashMap::from([(pc_a.clone(), vec![existing_hint])]);
+
+        let new_hint: Box<dyn Any> = Box::new(2_u8);
+        let extension =
+            HintExtension::from([(pc_a.clone(), vec![new_hint]), (pc_b.clone(), vec![])]);
+
+        apply_hint_extension(&mut hint_data_dictionary, extension);
+
+        assert_eq!(hint_data_dictionary.get(&pc_a).unwrap().len(), 2);
+        assert!(hint_data_dictionary.contains_key(&pc_b));
+    }
+}
@@ -0,0 +1,17 @@
+use crate::types::relocatable::Relocatable;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum HintError {
+    #[error(transparent)]
+    Internal(#[from] VirtualMachineError),
+    #[error("Hint references an unknown identifier: ids.{0}")]
+    UnknownIdentifier(String),
+    #[error("Identifier ids.{0}, found at {1}, is not relocatable")]
+    IdentifierNotRelocatable(String, Relocatable),
+    #[error("Identifier ids.{0}, found at {1}, is not an integer")]
+    IdentifierNotInteger(String, Relocatable),
+    #[error("ids.{0} has cairo_type {1:?}, expected {2}")]
+    IdentifierNotTypeMatching(String, Option<String>, &'static str),
+}
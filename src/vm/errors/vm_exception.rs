@@ -0,0 +1,61 @@
+use crate::types::relocatable::Relocatable;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::hints::hint_utils::get_traceback_entries;
+use crate::vm::vm_memory::memory::MemoryProxy;
+
+///Wraps a `VirtualMachineError` with the pc it failed at and the reconstructed call-stack
+///traceback, so the VM's exception-reporting path can format it into a user-facing error.
+#[derive(Debug, PartialEq)]
+pub struct VmException {
+    pub pc: Relocatable,
+    pub inner_exc: VirtualMachineError,
+    pub traceback: Option<Vec<(usize, usize)>>,
+}
+
+impl VmException {
+    pub fn from_vm_error(
+        inner_exc: VirtualMachineError,
+        memory: &MemoryProxy,
+        pc: Relocatable,
+        fp: Relocatable,
+    ) -> Self {
+        let traceback = get_traceback_entries(memory, &fp);
+        VmException {
+            pc,
+            inner_exc,
+            traceback: if traceback.is_empty() {
+                None
+            } else {
+                Some(traceback)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::hints::execute_hint::get_vm_proxy;
+    use crate::vm::vm_core::VirtualMachine;
+
+    #[test]
+    fn from_vm_error_passes_through_pc_and_inner_exc_with_no_traceback() {
+        let mut vm = vm!();
+        vm.segments.add(&mut vm.memory, None);
+
+        // fp = (0, 1): get_traceback_entries can't even read a saved fp this close to the
+        // start of the segment, so the traceback comes back empty and must collapse to None.
+        let vm_proxy = get_vm_proxy(&mut vm);
+        let exc = VmException::from_vm_error(
+            VirtualMachineError::NoRangeCheckBuiltin,
+            &vm_proxy.memory,
+            relocatable!(0, 50),
+            relocatable!(0, 1),
+        );
+        assert_eq!(exc.pc, relocatable!(0, 50));
+        assert_eq!(exc.inner_exc, VirtualMachineError::NoRangeCheckBuiltin);
+        assert_eq!(exc.traceback, None);
+    }
+}
@@ -8,8 +8,11 @@ use crate::vm::runners::builtin_runner::BuiltinRunner;
 use crate::vm::vm_core::VMProxy;
 use crate::vm::vm_memory::memory::MemoryProxy;
 use crate::vm::{
-    context::run_context::RunContext, errors::vm_errors::VirtualMachineError,
-    hints::execute_hint::HintReference, runners::builtin_runner::RangeCheckBuiltinRunner,
+    context::run_context::RunContext,
+    errors::hint_errors::HintError,
+    errors::vm_errors::VirtualMachineError,
+    hints::execute_hint::HintReference,
+    runners::builtin_runner::RangeCheckBuiltinRunner,
 };
 use num_bigint::BigInt;
 use num_traits::{Signed, ToPrimitive};
@@ -49,14 +52,16 @@ pub fn get_ptr_from_var_name(
     vm_proxy: &VMProxy,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-) -> Result<Relocatable, VirtualMachineError> {
+) -> Result<Relocatable, HintError> {
     let var_addr = get_relocatable_from_var_name(var_name, vm_proxy, ids_data, ap_tracking)?;
     //Add immediate if present in reference
     let hint_reference = ids_data
         .get(&String::from(var_name))
-        .ok_or(VirtualMachineError::FailedToGetIds)?;
+        .ok_or_else(|| HintError::UnknownIdentifier(var_name.to_string()))?;
     if hint_reference.dereference {
-        let value = vm_proxy.memory.get_relocatable(&var_addr)?;
+        let value = vm_proxy.memory.get_relocatable(&var_addr).map_err(|_| {
+            HintError::IdentifierNotRelocatable(var_name.to_string(), var_addr.clone())
+        })?;
         if let Some(immediate) = &hint_reference.immediate {
             let modified_value = relocatable!(
                 value.segment_index,
@@ -75,13 +80,13 @@ fn apply_ap_tracking_correction(
     ap: &Relocatable,
     ref_ap_tracking: &ApTracking,
     hint_ap_tracking: &ApTracking,
-) -> Result<MaybeRelocatable, VirtualMachineError> {
+) -> Result<MaybeRelocatable, HintError> {
     // check that both groups are the same
     if ref_ap_tracking.group != hint_ap_tracking.group {
-        return Err(VirtualMachineError::InvalidTrackingGroup(
+        return Err(HintError::Internal(VirtualMachineError::InvalidTrackingGroup(
             ref_ap_tracking.group,
             hint_ap_tracking.group,
-        ));
+        )));
     }
     let ap_diff = hint_ap_tracking.offset - ref_ap_tracking.offset;
 
@@ -99,12 +104,12 @@ pub fn compute_addr_from_reference(
     //TODO: Check if this option is necessary
     hint_ap_tracking: Option<&ApTracking>,
     //TODO: Change this to Result
-) -> Result<Option<MaybeRelocatable>, VirtualMachineError> {
+) -> Result<Option<MaybeRelocatable>, HintError> {
     let base_addr = match hint_reference.register {
         Register::FP => run_context.fp.clone(),
         Register::AP => {
             if hint_ap_tracking.is_none() || hint_reference.ap_tracking_data.is_none() {
-                return Err(VirtualMachineError::NoneApTrackingData);
+                return Err(HintError::Internal(VirtualMachineError::NoneApTrackingData));
             }
 
             if let MaybeRelocatable::RelocatableValue(ref relocatable) = run_context.ap {
@@ -118,7 +123,9 @@ pub fn compute_addr_from_reference(
                     hint_ap_tracking.unwrap(),
                 )?
             } else {
-                return Err(VirtualMachineError::InvalidApValue(run_context.ap.clone()));
+                return Err(HintError::Internal(VirtualMachineError::InvalidApValue(
+                    run_context.ap.clone(),
+                )));
             }
         }
     };
@@ -172,16 +179,16 @@ pub fn get_address_from_var_name(
     vm_proxy: &VMProxy,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-) -> Result<MaybeRelocatable, VirtualMachineError> {
+) -> Result<MaybeRelocatable, HintError> {
     compute_addr_from_reference(
         ids_data
             .get(var_name)
-            .ok_or(VirtualMachineError::FailedToGetIds)?,
+            .ok_or_else(|| HintError::UnknownIdentifier(var_name.to_string()))?,
         vm_proxy.run_context,
         &vm_proxy.memory,
         Some(ap_tracking),
     )?
-    .ok_or(VirtualMachineError::FailedToGetIds)
+    .ok_or(HintError::Internal(VirtualMachineError::FailedToGetIds))
 }
 
 pub fn insert_value_from_var_name(
@@ -190,9 +197,12 @@ pub fn insert_value_from_var_name(
     vm_proxy: &mut VMProxy,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-) -> Result<(), VirtualMachineError> {
+) -> Result<(), HintError> {
     let var_address = get_relocatable_from_var_name(var_name, vm_proxy, ids_data, ap_tracking)?;
-    vm_proxy.memory.insert_value(&var_address, value)
+    vm_proxy
+        .memory
+        .insert_value(&var_address, value)
+        .map_err(HintError::Internal)
 }
 
 //Inserts value into ap
@@ -200,15 +210,17 @@ pub fn insert_value_into_ap(
     memory: &mut MemoryProxy,
     run_context: &RunContext,
     value: impl Into<MaybeRelocatable>,
-) -> Result<(), VirtualMachineError> {
-    memory.insert_value(
-        &(run_context
-            .ap
-            .clone()
-            .try_into()
-            .map_err(VirtualMachineError::MemoryError)?),
-        value,
-    )
+) -> Result<(), HintError> {
+    memory
+        .insert_value(
+            &(run_context
+                .ap
+                .clone()
+                .try_into()
+                .map_err(VirtualMachineError::MemoryError)?),
+            value,
+        )
+        .map_err(HintError::Internal)
 }
 
 //Gets the address of a variable name.
@@ -219,10 +231,12 @@ pub fn get_relocatable_from_var_name(
     vm_proxy: &VMProxy,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-) -> Result<Relocatable, VirtualMachineError> {
+) -> Result<Relocatable, HintError> {
     match get_address_from_var_name(var_name, vm_proxy, ids_data, ap_tracking)? {
         MaybeRelocatable::RelocatableValue(relocatable) => Ok(relocatable),
-        address => Err(VirtualMachineError::ExpectedRelocatable(address)),
+        // compute_addr_from_reference only ever builds a RelocatableValue address, so this
+        // branch can't trigger today; kept as a defensive fallback instead of an unwrap.
+        _not_relocatable => Err(HintError::UnknownIdentifier(var_name.to_string())),
     }
 }
 
@@ -234,31 +248,33 @@ pub fn get_integer_from_var_name<'a>(
     vm_proxy: &'a VMProxy,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-) -> Result<&'a BigInt, VirtualMachineError> {
+) -> Result<&'a BigInt, HintError> {
     let relocatable = get_relocatable_from_var_name(var_name, vm_proxy, ids_data, ap_tracking)?;
-    vm_proxy.memory.get_integer(&relocatable)
+    vm_proxy
+        .memory
+        .get_integer(&relocatable)
+        .map_err(|_| HintError::IdentifierNotInteger(var_name.to_string(), relocatable))
 }
 
 ///Implements hint: memory[ap] = segments.add()
-pub fn add_segment(vm_proxy: &mut VMProxy) -> Result<(), VirtualMachineError> {
+pub fn add_segment(vm_proxy: &mut VMProxy) -> Result<(), HintError> {
     let new_segment_base = vm_proxy.memory.add_segment(vm_proxy.segments);
     insert_value_into_ap(&mut vm_proxy.memory, vm_proxy.run_context, new_segment_base)
 }
 
 //Implements hint: vm_enter_scope()
-pub fn enter_scope(
-    exec_scopes_proxy: &mut ExecutionScopesProxy,
-) -> Result<(), VirtualMachineError> {
+pub fn enter_scope(exec_scopes_proxy: &mut ExecutionScopesProxy) -> Result<(), HintError> {
     exec_scopes_proxy.enter_scope(HashMap::new());
     Ok(())
 }
 
 //  Implements hint:
 //  %{ vm_exit_scope() %}
-pub fn exit_scope(exec_scopes_proxy: &mut ExecutionScopesProxy) -> Result<(), VirtualMachineError> {
+pub fn exit_scope(exec_scopes_proxy: &mut ExecutionScopesProxy) -> Result<(), HintError> {
     exec_scopes_proxy
         .exit_scope()
         .map_err(VirtualMachineError::MainScopeError)
+        .map_err(HintError::Internal)
 }
 
 //  Implements hint:
@@ -268,7 +284,7 @@ pub fn memcpy_enter_scope(
     exec_scopes_proxy: &mut ExecutionScopesProxy,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-) -> Result<(), VirtualMachineError> {
+) -> Result<(), HintError> {
     let len: Box<dyn Any> =
         Box::new(get_integer_from_var_name("len", &vm_proxy, ids_data, ap_tracking)?.clone());
     exec_scopes_proxy.enter_scope(HashMap::from([(String::from("n"), len)]));
@@ -285,7 +301,7 @@ pub fn memcpy_continue_copying(
     exec_scopes_proxy: &mut ExecutionScopesProxy,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-) -> Result<(), VirtualMachineError> {
+) -> Result<(), HintError> {
     // get `n` variable from vm scope
     let n = exec_scopes_proxy.get_int_ref("n")?;
     // this variable will hold the value of `n - 1`
@@ -313,6 +329,118 @@ pub fn memcpy_continue_copying(
     Ok(())
 }
 
+///A Cairo struct that can be read out of VM memory starting at a base address
+pub trait CairoType: Sized {
+    const CAIRO_TYPE: &'static str;
+
+    fn from_base_addr(base_addr: Relocatable, vm_proxy: &VMProxy) -> Result<Self, HintError>;
+}
+
+///A Cairo `BigInt3`, i.e. a 3-limb big integer represented as three consecutive felts.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BigInt3 {
+    pub d0: BigInt,
+    pub d1: BigInt,
+    pub d2: BigInt,
+}
+
+impl CairoType for BigInt3 {
+    const CAIRO_TYPE: &'static str = "BigInt3";
+
+    fn from_base_addr(base_addr: Relocatable, vm_proxy: &VMProxy) -> Result<Self, HintError> {
+        Ok(BigInt3 {
+            d0: vm_proxy
+                .memory
+                .get_integer(&base_addr)
+                .map_err(HintError::Internal)?
+                .clone(),
+            d1: vm_proxy
+                .memory
+                .get_integer(&relocatable!(base_addr.segment_index, base_addr.offset + 1))
+                .map_err(HintError::Internal)?
+                .clone(),
+            d2: vm_proxy
+                .memory
+                .get_integer(&relocatable!(base_addr.segment_index, base_addr.offset + 2))
+                .map_err(HintError::Internal)?
+                .clone(),
+        })
+    }
+}
+
+///A Cairo `EcPoint`, i.e. a point on an elliptic curve represented as two `BigInt3` coordinates.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EcPoint {
+    pub x: BigInt3,
+    pub y: BigInt3,
+}
+
+impl CairoType for EcPoint {
+    const CAIRO_TYPE: &'static str = "EcPoint";
+
+    fn from_base_addr(base_addr: Relocatable, vm_proxy: &VMProxy) -> Result<Self, HintError> {
+        Ok(EcPoint {
+            x: BigInt3::from_base_addr(base_addr.clone(), vm_proxy)?,
+            y: BigInt3::from_base_addr(
+                relocatable!(base_addr.segment_index, base_addr.offset + 3),
+                vm_proxy,
+            )?,
+        })
+    }
+}
+
+///Reads a composite Cairo value (e.g. `ids.point` typed as `EcPoint`) in one call
+pub fn get_struct_from_var_name<T: CairoType>(
+    var_name: &str,
+    vm_proxy: &VMProxy,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<T, HintError> {
+    let hint_reference = ids_data
+        .get(var_name)
+        .ok_or_else(|| HintError::UnknownIdentifier(var_name.to_string()))?;
+    if hint_reference.cairo_type.as_deref() != Some(T::CAIRO_TYPE) {
+        return Err(HintError::IdentifierNotTypeMatching(
+            var_name.to_string(),
+            hint_reference.cairo_type.clone(),
+            T::CAIRO_TYPE,
+        ));
+    }
+    let base_addr = get_ptr_from_var_name(var_name, vm_proxy, ids_data, ap_tracking)?;
+    T::from_base_addr(base_addr, vm_proxy)
+}
+
+///Hard cap on the number of frames `get_traceback_entries` will walk
+pub const MAX_TRACEBACK_ENTRIES: usize = 20;
+
+///Walks the fp chain to reconstruct the Cairo call stack, most-recent-call-last
+pub fn get_traceback_entries(memory: &MemoryProxy, fp: &Relocatable) -> Vec<(usize, usize)> {
+    let mut entries = Vec::new();
+    let mut fp = fp.clone();
+    for _ in 0..MAX_TRACEBACK_ENTRIES {
+        if fp.offset < 2 {
+            break;
+        }
+        let saved_fp_addr = relocatable!(fp.segment_index, fp.offset - 2);
+        let ret_pc_addr = relocatable!(fp.segment_index, fp.offset - 1);
+        let saved_fp = match memory.get_relocatable(&saved_fp_addr) {
+            Ok(saved_fp) => saved_fp.clone(),
+            Err(_) => break,
+        };
+        let ret_pc = match memory.get_relocatable(&ret_pc_addr) {
+            Ok(ret_pc) => ret_pc.clone(),
+            Err(_) => break,
+        };
+        if saved_fp == fp {
+            break;
+        }
+        entries.push((fp.offset, ret_pc.offset));
+        fp = saved_fp;
+    }
+    entries.reverse();
+    entries
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -373,9 +501,140 @@ mod tests {
         let vm_proxy = &mut get_vm_proxy(&mut vm);
         assert_eq!(
             get_integer_from_var_name(var_name, &vm_proxy, &ids_data, &ApTracking::default()),
-            Err(VirtualMachineError::ExpectedInteger(
-                MaybeRelocatable::from((0, 0))
+            Err(HintError::IdentifierNotInteger(
+                var_name.to_string(),
+                relocatable!(0, 0)
+            ))
+        );
+    }
+
+    #[test]
+    fn get_struct_from_var_name_big_int3_valid() {
+        let mut vm = vm!();
+        vm.segments.add(&mut vm.memory, None); // segment 0: stack
+        vm.segments.add(&mut vm.memory, None); // segment 1: the BigInt3 itself
+        vm.run_context.fp = MaybeRelocatable::from((0, 1));
+
+        let var_name: &str = "value";
+        let mut ids_data = HashMap::new();
+        ids_data.insert(
+            var_name.to_string(),
+            HintReference {
+                cairo_type: Some("BigInt3".to_string()),
+                ..HintReference::new_simple(-1)
+            },
+        );
+
+        // ids.value is a pointer, stored at (0, 0), to the struct's base at (1, 0)
+        vm.memory
+            .insert(
+                &MaybeRelocatable::from((0, 0)),
+                &MaybeRelocatable::from((1, 0)),
+            )
+            .unwrap();
+        vm.memory
+            .insert(
+                &MaybeRelocatable::from((1, 0)),
+                &MaybeRelocatable::from(bigint!(1)),
+            )
+            .unwrap();
+        vm.memory
+            .insert(
+                &MaybeRelocatable::from((1, 1)),
+                &MaybeRelocatable::from(bigint!(2)),
+            )
+            .unwrap();
+        vm.memory
+            .insert(
+                &MaybeRelocatable::from((1, 2)),
+                &MaybeRelocatable::from(bigint!(3)),
+            )
+            .unwrap();
+
+        let vm_proxy = get_vm_proxy(&mut vm);
+        assert_eq!(
+            get_struct_from_var_name::<BigInt3>(
+                var_name,
+                &vm_proxy,
+                &ids_data,
+                &ApTracking::default()
+            ),
+            Ok(BigInt3 {
+                d0: bigint!(1),
+                d1: bigint!(2),
+                d2: bigint!(3)
+            })
+        );
+    }
+
+    #[test]
+    fn get_struct_from_var_name_type_mismatch() {
+        let mut vm = vm!();
+        vm.segments.add(&mut vm.memory, None);
+        vm.run_context.fp = MaybeRelocatable::from((0, 1));
+
+        let var_name: &str = "value";
+        let mut ids_data = HashMap::new();
+        ids_data.insert(
+            var_name.to_string(),
+            HintReference {
+                cairo_type: Some("EcPoint".to_string()),
+                ..HintReference::new_simple(0)
+            },
+        );
+
+        let vm_proxy = get_vm_proxy(&mut vm);
+        assert_eq!(
+            get_struct_from_var_name::<BigInt3>(
+                var_name,
+                &vm_proxy,
+                &ids_data,
+                &ApTracking::default()
+            ),
+            Err(HintError::IdentifierNotTypeMatching(
+                var_name.to_string(),
+                Some("EcPoint".to_string()),
+                "BigInt3"
             ))
         );
     }
+
+    #[test]
+    fn get_traceback_entries_two_frames() {
+        let mut vm = vm!();
+        vm.segments.add(&mut vm.memory, None);
+
+        // innermost frame: fp = (0, 10), caller fp = (0, 4), return pc = (0, 100)
+        vm.memory
+            .insert(
+                &MaybeRelocatable::from((0, 8)),
+                &MaybeRelocatable::from((0, 4)),
+            )
+            .unwrap();
+        vm.memory
+            .insert(
+                &MaybeRelocatable::from((0, 9)),
+                &MaybeRelocatable::from((0, 100)),
+            )
+            .unwrap();
+        // outermost frame: fp = (0, 4) has no caller, its saved fp points back at itself
+        vm.memory
+            .insert(
+                &MaybeRelocatable::from((0, 2)),
+                &MaybeRelocatable::from((0, 4)),
+            )
+            .unwrap();
+        vm.memory
+            .insert(
+                &MaybeRelocatable::from((0, 3)),
+                &MaybeRelocatable::from((0, 200)),
+            )
+            .unwrap();
+
+        let vm_proxy = get_vm_proxy(&mut vm);
+        assert_eq!(
+            get_traceback_entries(&vm_proxy.memory, &relocatable!(0, 10)),
+            vec![(10, 100)]
+        );
+    }
 }